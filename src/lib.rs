@@ -0,0 +1,41 @@
+// Copyright 2015, 2016 Ethcore (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Fixed-size byte array primitives (`H256`, `Address`, `Bloom`, ...) used as the output of
+//! hash functions. See the `hash` module for details.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate rand;
+#[cfg(feature = "std")]
+extern crate rustc_serialize;
+#[cfg(feature = "std")]
+extern crate libc;
+#[cfg(feature = "serialize")]
+extern crate serde;
+#[cfg(feature = "rlp")]
+extern crate rlp;
+#[macro_use]
+extern crate heapsize;
+#[macro_use]
+extern crate crunchy;
+extern crate sha3;
+extern crate uint;
+
+pub mod hash;
+
+pub use hash::*;